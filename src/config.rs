@@ -15,7 +15,12 @@ use activitystreams::{
 use config::Environment;
 use http_signature_normalization_actix::prelude::{VerifyDigest, VerifySignature};
 use sha2::{Digest, Sha256};
-use std::{net::IpAddr, path::PathBuf};
+use std::{
+    fs::File,
+    io::BufReader,
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+};
 use uuid::Uuid;
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -34,6 +39,58 @@ pub(crate) struct ParsedConfig {
     telegram_token: Option<String>,
     telegram_admin_handle: Option<String>,
     api_token: Option<String>,
+    api_token_hash: Option<String>,
+    tls_key: Option<PathBuf>,
+    tls_cert: Option<PathBuf>,
+    prometheus_addr: Option<IpAddr>,
+    prometheus_port: Option<u16>,
+    proxy_url: Option<IriString>,
+    proxy_username: Option<String>,
+    proxy_password: Option<String>,
+    deliver_concurrency: u64,
+    client_timeout: u64,
+    signature_threads: Option<usize>,
+    footer_blurb: Option<String>,
+    local_blurb: Option<String>,
+    local_domains: Option<String>,
+    repository_commit_base: String,
+}
+
+/// The outbound proxy to route federation requests through, with optional basic-auth
+/// credentials.
+#[derive(Clone)]
+pub(crate) struct ProxyConfig {
+    pub(crate) url: IriString,
+    pub(crate) auth: Option<(String, String)>,
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &redact_userinfo(&self.url))
+            .field("auth", &self.auth.as_ref().map(|_| "[redacted]"))
+            .finish()
+    }
+}
+
+/// Strips any `user:pass@` userinfo embedded in a URL's authority before it's logged, so
+/// credentials smuggled into `proxy_url` can't leak through `Debug` output.
+fn redact_userinfo(url: &IriString) -> String {
+    let s = url.to_string();
+
+    match (s.find("://"), s.find('@')) {
+        (Some(scheme_end), Some(at)) if at > scheme_end => {
+            format!("{}[redacted]{}", &s[..scheme_end + 3], &s[at..])
+        }
+        _ => s,
+    }
+}
+
+/// Paths to the PEM-encoded private key and certificate chain used for native TLS termination.
+#[derive(Clone, Debug)]
+pub(crate) struct TlsConfig {
+    key: PathBuf,
+    cert: PathBuf,
 }
 
 #[derive(Clone)]
@@ -52,6 +109,20 @@ pub struct Config {
     telegram_token: Option<String>,
     telegram_admin_handle: Option<String>,
     api_token: Option<String>,
+    api_token_hash: Option<String>,
+    tls: Option<TlsConfig>,
+    prometheus_addr: Option<IpAddr>,
+    prometheus_port: Option<u16>,
+    proxy_url: Option<IriString>,
+    proxy_username: Option<String>,
+    proxy_password: Option<String>,
+    deliver_concurrency: u64,
+    client_timeout: u64,
+    signature_threads: usize,
+    footer_blurb: Option<String>,
+    local_blurb: Option<String>,
+    local_domains: Vec<String>,
+    repository_commit_base: String,
 }
 
 #[derive(Debug)]
@@ -99,6 +170,17 @@ impl std::fmt::Debug for Config {
             .field("telegram_token", &"[redacted]")
             .field("telegram_admin_handle", &self.telegram_admin_handle)
             .field("api_token", &"[redacted]")
+            .field("api_token_hash", &"[redacted]")
+            .field("tls", &self.tls)
+            .field("prometheus_bind_address", &self.prometheus_bind_address())
+            .field("proxy_config", &self.proxy_config())
+            .field("deliver_concurrency", &self.deliver_concurrency)
+            .field("client_timeout", &self.client_timeout)
+            .field("signature_threads", &self.signature_threads)
+            .field("footer_blurb", &self.footer_blurb)
+            .field("local_blurb", &self.local_blurb)
+            .field("local_domains", &self.local_domains)
+            .field("repository_commit_base", &self.repository_commit_base)
             .finish()
     }
 }
@@ -120,6 +202,24 @@ impl Config {
             .set_default("telegram_token", None as Option<&str>)?
             .set_default("telegram_admin_handle", None as Option<&str>)?
             .set_default("api_token", None as Option<&str>)?
+            .set_default("api_token_hash", None as Option<&str>)?
+            .set_default("tls_key", None as Option<&str>)?
+            .set_default("tls_cert", None as Option<&str>)?
+            .set_default("prometheus_addr", None as Option<&str>)?
+            .set_default("prometheus_port", None as Option<u64>)?
+            .set_default("proxy_url", None as Option<&str>)?
+            .set_default("proxy_username", None as Option<&str>)?
+            .set_default("proxy_password", None as Option<&str>)?
+            .set_default("deliver_concurrency", 8u64)?
+            .set_default("client_timeout", 10u64)?
+            .set_default("signature_threads", None as Option<u64>)?
+            .set_default("footer_blurb", None as Option<&str>)?
+            .set_default("local_blurb", None as Option<&str>)?
+            .set_default(
+                "repository_commit_base",
+                "https://git.asonix.dog/asonix/relay/commit/",
+            )?
+            .set_default("local_domains", None as Option<&str>)?
             .add_source(Environment::default())
             .build()?;
 
@@ -128,6 +228,40 @@ impl Config {
         let scheme = if config.https { "https" } else { "http" };
         let base_uri = iri!(format!("{}://{}", scheme, config.hostname)).into_absolute();
 
+        let signature_threads = config
+            .signature_threads
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, usize::from));
+
+        let local_domains = config
+            .local_domains
+            .as_deref()
+            .map(|domains| domains.split(',').map(|s| s.trim().to_string()).collect())
+            .unwrap_or_default();
+
+        let tls = match (config.tls_key, config.tls_cert) {
+            (Some(key), Some(cert)) => Some(TlsConfig { key, cert }),
+            (None, None) => None,
+            (Some(_), None) => {
+                return Err(Error::from(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "tls_key is set but tls_cert is missing",
+                )));
+            }
+            (None, Some(_)) => {
+                return Err(Error::from(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "tls_cert is set but tls_key is missing",
+                )));
+            }
+        };
+
+        if config.proxy_username.is_some() != config.proxy_password.is_some() {
+            return Err(Error::from(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "proxy_username and proxy_password must both be set or both be absent",
+            )));
+        }
+
         Ok(Config {
             hostname: config.hostname,
             addr: config.addr,
@@ -143,6 +277,20 @@ impl Config {
             telegram_token: config.telegram_token,
             telegram_admin_handle: config.telegram_admin_handle,
             api_token: config.api_token,
+            api_token_hash: config.api_token_hash,
+            tls,
+            prometheus_addr: config.prometheus_addr,
+            prometheus_port: config.prometheus_port,
+            proxy_url: config.proxy_url,
+            proxy_username: config.proxy_username,
+            proxy_password: config.proxy_password,
+            deliver_concurrency: config.deliver_concurrency,
+            client_timeout: config.client_timeout,
+            signature_threads,
+            footer_blurb: config.footer_blurb,
+            local_blurb: config.local_blurb,
+            local_domains,
+            repository_commit_base: config.repository_commit_base,
         })
     }
 
@@ -180,23 +328,103 @@ impl Config {
     }
 
     pub(crate) fn admin_config(&self) -> Option<actix_web::web::Data<AdminConfig>> {
-        if let Some(api_token) = &self.api_token {
-            match AdminConfig::build(api_token) {
-                Ok(conf) => Some(actix_web::web::Data::new(conf)),
-                Err(e) => {
-                    tracing::error!("Error creating admin config: {}", e);
-                    None
-                }
+        if self.api_token.is_none() && self.api_token_hash.is_none() {
+            return None;
+        }
+
+        match AdminConfig::build(self.api_token.as_deref(), self.api_token_hash.as_deref()) {
+            Ok(conf) => Some(actix_web::web::Data::new(conf)),
+            Err(e) => {
+                tracing::error!("Error creating admin config: {}", e);
+                None
             }
-        } else {
-            None
         }
     }
 
+    /// Hash a plaintext admin API token with bcrypt so it can be stored as `api_token_hash`
+    /// instead of in plaintext.
+    pub(crate) fn hash_api_token(token: &str) -> Result<String, bcrypt::BcryptError> {
+        bcrypt::hash(token, bcrypt::DEFAULT_COST)
+    }
+
     pub(crate) fn bind_address(&self) -> (IpAddr, u16) {
         (self.addr, self.port)
     }
 
+    /// Build a rustls `ServerConfig` from the configured key and cert, if native TLS
+    /// termination is enabled.
+    ///
+    /// Returns `Ok(None)` only when no `tls_key`/`tls_cert` were configured at all. Once TLS
+    /// has been requested, any failure to load or parse it is returned as an `Err` rather than
+    /// silently falling back to cleartext — an operator who misconfigures TLS should see the
+    /// server refuse to start, not find out later that it served plaintext on the TLS port.
+    pub(crate) fn tls_config(&self) -> Result<Option<rustls::ServerConfig>, Error> {
+        let Some(tls) = self.tls.as_ref() else {
+            return Ok(None);
+        };
+
+        let cert_file = File::open(&tls.cert).map_err(|e| {
+            Error::from(std::io::Error::new(
+                e.kind(),
+                format!("Error opening tls_cert: {}", e),
+            ))
+        })?;
+        let key_bytes = std::fs::read(&tls.key).map_err(|e| {
+            Error::from(std::io::Error::new(
+                e.kind(),
+                format!("Error opening tls_key: {}", e),
+            ))
+        })?;
+
+        let cert_chain = rustls_pemfile::certs(&mut BufReader::new(cert_file))
+            .map_err(|e| {
+                Error::from(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Error parsing tls_cert: {}", e),
+                ))
+            })?
+            .into_iter()
+            .map(rustls::Certificate)
+            .collect();
+
+        // Operators commonly have PKCS#1 (`BEGIN RSA PRIVATE KEY`) or SEC1
+        // (`BEGIN EC PRIVATE KEY`) keys rather than PKCS#8, so fall back through each format.
+        let key = rustls_pemfile::pkcs8_private_keys(&mut std::io::Cursor::new(&key_bytes))
+            .ok()
+            .and_then(|mut keys| keys.pop())
+            .or_else(|| {
+                rustls_pemfile::rsa_private_keys(&mut std::io::Cursor::new(&key_bytes))
+                    .ok()
+                    .and_then(|mut keys| keys.pop())
+            })
+            .or_else(|| {
+                rustls_pemfile::ec_private_keys(&mut std::io::Cursor::new(&key_bytes))
+                    .ok()
+                    .and_then(|mut keys| keys.pop())
+            });
+
+        let Some(key) = key else {
+            return Err(Error::from(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "No supported private key (PKCS8, PKCS1, or SEC1) found in tls_key",
+            )));
+        };
+        let key = rustls::PrivateKey(key);
+
+        let server_config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(cert_chain, key)
+            .map_err(|e| {
+                Error::from(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Error building TLS config: {}", e),
+                ))
+            })?;
+
+        Ok(Some(server_config))
+    }
+
     pub(crate) fn debug(&self) -> bool {
         self.debug
     }
@@ -266,10 +494,75 @@ impl Config {
         &self.source_repo
     }
 
+    /// A link to the exact source revision this relay was built from, joining
+    /// `repository_commit_base` with the compiled-in `GIT_HASH`. Falls back to
+    /// [`Self::source_code`] when no git hash was baked in at build time.
+    pub(crate) fn source_code_for_commit(&self) -> IriString {
+        let Some(hash) = Self::git_hash() else {
+            return self.source_repo.clone();
+        };
+
+        match IriString::try_from(format!("{}{}", self.repository_commit_base, hash)) {
+            Ok(iri) => iri,
+            Err(e) => {
+                tracing::error!("Invalid repository_commit_base, {}", e);
+                self.source_repo.clone()
+            }
+        }
+    }
+
     pub(crate) fn opentelemetry_url(&self) -> Option<&IriString> {
         self.opentelemetry_url.as_ref()
     }
 
+    /// The socket to bind the Prometheus scrape endpoint to, if metrics are enabled.
+    pub(crate) fn prometheus_bind_address(&self) -> Option<SocketAddr> {
+        let addr = self.prometheus_addr?;
+        let port = self.prometheus_port?;
+
+        Some(SocketAddr::new(addr, port))
+    }
+
+    /// The outbound proxy federation requests should be routed through, if configured.
+    pub(crate) fn proxy_config(&self) -> Option<ProxyConfig> {
+        let url = self.proxy_url.clone()?;
+
+        let auth = self.proxy_username.clone().zip(self.proxy_password.clone());
+
+        Some(ProxyConfig { url, auth })
+    }
+
+    /// How many deliveries the `deliver_many` fan-out is allowed to run concurrently.
+    pub(crate) fn deliver_concurrency(&self) -> u64 {
+        self.deliver_concurrency
+    }
+
+    /// Timeout, in seconds, applied to the outbound HTTP client used for federation requests.
+    pub(crate) fn client_timeout(&self) -> u64 {
+        self.client_timeout
+    }
+
+    /// Number of threads in the dedicated pool used for verifying HTTP signatures and hashing
+    /// delivery bodies, so that this CPU-bound work never blocks the actix worker threads.
+    pub(crate) fn signature_threads(&self) -> usize {
+        self.signature_threads
+    }
+
+    /// Operator-supplied blurb rendered in the index page footer.
+    pub(crate) fn footer_blurb(&self) -> Option<&str> {
+        self.footer_blurb.as_deref()
+    }
+
+    /// Operator-supplied blurb rendered above the node list on the index page.
+    pub(crate) fn local_blurb(&self) -> Option<&str> {
+        self.local_blurb.as_deref()
+    }
+
+    /// Hostnames treated as local for the purposes of splitting the index page's node list.
+    pub(crate) fn local_domains(&self) -> &[String] {
+        &self.local_domains
+    }
+
     pub(crate) fn telegram_info(&self) -> Option<(&str, &str)> {
         self.telegram_token.as_deref().and_then(|token| {
             let handle = self.telegram_admin_handle.as_deref()?;
@@ -0,0 +1,48 @@
+use crate::error::Error;
+use activitystreams::iri_string::types::IriString;
+
+#[derive(Clone, Debug)]
+pub(crate) struct NodeInstance {
+    pub(crate) reg: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct NodeInfo {
+    pub(crate) reg: bool,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Node {
+    pub(crate) base: IriString,
+    pub(crate) instance: Option<NodeInstance>,
+    pub(crate) info: Option<NodeInfo>,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct NodeCache {
+    nodes: Vec<Node>,
+}
+
+impl NodeCache {
+    pub(crate) async fn nodes(&self) -> Result<Vec<Node>, Error> {
+        Ok(self.nodes.clone())
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct State {
+    node_cache: NodeCache,
+}
+
+impl State {
+    pub(crate) fn node_cache(&self) -> &NodeCache {
+        &self.node_cache
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct ActorCache;
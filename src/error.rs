@@ -0,0 +1,49 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub(crate) enum ErrorKind {
+    FlushBuffer,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::FlushBuffer => write!(f, "Failed to flush the output buffer"),
+        }
+    }
+}
+
+impl std::error::Error for ErrorKind {}
+
+/// The relay's top-level error type. Wraps any error produced by the server so a single
+/// `?` works across config parsing, IO, templating, and federation code.
+#[derive(Debug)]
+pub struct Error(Box<dyn std::error::Error + Send + Sync + 'static>);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.0.as_ref())
+    }
+}
+
+impl<E> From<E> for Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn from(e: E) -> Self {
+        Error(Box::new(e))
+    }
+}
+
+impl actix_web::ResponseError for Error {
+    fn error_response(&self) -> actix_web::HttpResponse {
+        tracing::error!("Error: {}", self);
+        actix_web::HttpResponse::InternalServerError().finish()
+    }
+}
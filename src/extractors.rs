@@ -0,0 +1,85 @@
+use crate::error::Error;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use std::future::{ready, Ready};
+
+/// The admin API token presented by a caller via the `X-Api-Token` header.
+#[derive(Clone)]
+pub(crate) struct XApiToken(String);
+
+impl XApiToken {
+    pub(crate) fn new(token: String) -> Self {
+        XApiToken(token)
+    }
+}
+
+impl FromRequest for XApiToken {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let token = req
+            .headers()
+            .get("X-Api-Token")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| XApiToken(value.to_string()));
+
+        ready(token.ok_or_else(|| {
+            Error::from(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Missing X-Api-Token header",
+            ))
+        }))
+    }
+}
+
+enum Verifier {
+    Hash(String),
+    Plain(String),
+}
+
+/// Holds however the operator chose to configure the admin API token (bcrypt hash or, for
+/// backwards compatibility, plaintext) and verifies presented tokens against it.
+pub(crate) struct AdminConfig {
+    verifier: Verifier,
+}
+
+impl AdminConfig {
+    pub(crate) fn build(
+        api_token: Option<&str>,
+        api_token_hash: Option<&str>,
+    ) -> Result<Self, Error> {
+        let verifier = match (api_token_hash, api_token) {
+            (Some(hash), _) => Verifier::Hash(hash.to_string()),
+            (None, Some(token)) => Verifier::Plain(token.to_string()),
+            (None, None) => {
+                return Err(Error::from(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "One of api_token or api_token_hash must be set",
+                )));
+            }
+        };
+
+        Ok(AdminConfig { verifier })
+    }
+
+    /// Verify a presented token against the configured admin token. Bcrypt-hashed tokens are
+    /// compared in constant time by `bcrypt::verify`; a plaintext fallback is compared with a
+    /// hand-rolled constant-time comparison so neither path leaks timing information.
+    pub(crate) fn verify(&self, presented: &XApiToken) -> bool {
+        match &self.verifier {
+            Verifier::Hash(hash) => bcrypt::verify(&presented.0, hash).unwrap_or(false),
+            Verifier::Plain(token) => constant_time_eq(token.as_bytes(), presented.0.as_bytes()),
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
@@ -0,0 +1,85 @@
+use actix_web::{web, App, HttpServer};
+use config::Config;
+use error::Error;
+
+mod config;
+mod data;
+mod error;
+mod extractors;
+mod metrics;
+mod middleware;
+mod requests;
+mod routes;
+mod signature_pool;
+mod templates;
+
+#[actix_web::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt::init();
+
+    if handle_cli()? {
+        return Ok(());
+    }
+
+    let config = Config::build()?;
+
+    signature_pool::install(config.signature_threads())?;
+
+    if let Some(addr) = config.prometheus_bind_address() {
+        metrics::install(addr)?;
+    }
+
+    let bind_address = config.bind_address();
+    let tls_config = config.tls_config()?;
+    let requests = requests::Requests::build(&config)?;
+    let admin_config = config.admin_config();
+
+    let data_config = web::Data::new(config);
+    let data_requests = web::Data::new(requests);
+
+    let server = HttpServer::new(move || {
+        let mut app = App::new()
+            .app_data(data_config.clone())
+            .app_data(data_requests.clone())
+            .configure(routes::routes);
+
+        if let Some(admin_config) = admin_config.clone() {
+            app = app.app_data(admin_config);
+        }
+
+        app
+    });
+
+    let server = if let Some(tls_config) = tls_config {
+        tracing::info!("Binding with native TLS termination enabled");
+        server.bind_rustls(bind_address, tls_config)?
+    } else {
+        server.bind(bind_address)?
+    };
+
+    server.run().await?;
+
+    Ok(())
+}
+
+/// Handles CLI subcommands that don't start the server. Returns `Ok(true)` when one was
+/// handled and the process should exit immediately.
+fn handle_cli() -> Result<bool, Error> {
+    let mut args = std::env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("hash-token") => {
+            let token = args.next().ok_or_else(|| {
+                Error::from(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "Usage: relay hash-token <token>",
+                ))
+            })?;
+
+            println!("{}", Config::hash_api_token(&token)?);
+
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
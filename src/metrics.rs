@@ -0,0 +1,31 @@
+use crate::error::Error;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use std::net::SocketAddr;
+
+/// Installs the global Prometheus recorder and starts its scrape listener on `addr`. Only
+/// called when [`crate::config::Config::prometheus_bind_address`] returns `Some`.
+pub(crate) fn install(addr: SocketAddr) -> Result<(), Error> {
+    PrometheusBuilder::new()
+        .with_http_listener(addr)
+        .install()?;
+
+    tracing::info!("Prometheus metrics listening on {}", addr);
+
+    Ok(())
+}
+
+pub(crate) fn record_activity_delivered() {
+    metrics::counter!("relay_activities_delivered_total", 1);
+}
+
+pub(crate) fn record_delivery_failure() {
+    metrics::counter!("relay_delivery_failures_total", 1);
+}
+
+pub(crate) fn record_delivery_duration(seconds: f64) {
+    metrics::histogram!("relay_delivery_duration_seconds", seconds);
+}
+
+pub(crate) fn set_node_cache_size(size: usize) {
+    metrics::gauge!("relay_node_cache_size", size as f64);
+}
@@ -0,0 +1,64 @@
+use crate::{
+    data::{ActorCache, State},
+    error::Error,
+    requests::Requests,
+    signature_pool,
+};
+use rsa::{pkcs1::DecodeRsaPublicKey, pkcs8::DecodePublicKey, Pkcs1v15Sign, RsaPublicKey};
+use sha2::{Digest, Sha256};
+
+/// HTTP signature verifier wired through [`crate::config::Config::signature_middleware`].
+/// Verification is CPU-bound (hashing + RSA math), so it's bridged onto the dedicated
+/// signature thread pool rather than run inline on the actix executor.
+pub(crate) struct MyVerify(pub(crate) Requests, pub(crate) ActorCache, pub(crate) State);
+
+impl MyVerify {
+    pub(crate) async fn verify(
+        &self,
+        public_key_pem: String,
+        signature: String,
+        signing_string: String,
+    ) -> Result<bool, Error> {
+        signature_pool::run_blocking(move || {
+            verify_signature(&public_key_pem, &signature, &signing_string)
+        })
+        .await?
+    }
+}
+
+/// Verifies an RSA-SHA256 HTTP signature against the actor's advertised public key. Accepts
+/// both PKCS#8 (`BEGIN PUBLIC KEY`) and PKCS#1 (`BEGIN RSA PUBLIC KEY`) encodings, since actors
+/// advertise either depending on implementation.
+///
+/// Returns `Err` for a malformed key or signature encoding (an actor can't be verified at all),
+/// and `Ok(false)` — never `Ok(true)` by default — for a key and signature that parse but don't
+/// match, so a bad actor can't talk its way past this check with an empty or garbage signature.
+fn verify_signature(
+    public_key_pem: &str,
+    signature: &str,
+    signing_string: &str,
+) -> Result<bool, Error> {
+    let public_key = RsaPublicKey::from_public_key_pem(public_key_pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(public_key_pem))
+        .map_err(|e| {
+            Error::from(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Invalid actor public key: {}", e),
+            ))
+        })?;
+
+    let signature_bytes = base64::decode(signature).map_err(|e| {
+        Error::from(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Invalid signature encoding: {}", e),
+        ))
+    })?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(signing_string.as_bytes());
+    let hashed = hasher.finalize();
+
+    Ok(public_key
+        .verify(Pkcs1v15Sign::new::<Sha256>(), &hashed, &signature_bytes)
+        .is_ok())
+}
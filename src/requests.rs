@@ -0,0 +1,110 @@
+use crate::{config::Config, error::Error, signature_pool};
+use futures::stream::{self, StreamExt};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant};
+
+/// Thin wrapper around the outbound HTTP client used for federation requests (actor/object
+/// fetches and activity deliveries), configured from [`Config`] once at startup.
+#[derive(Clone)]
+pub(crate) struct Requests {
+    client: reqwest::Client,
+}
+
+impl Requests {
+    pub(crate) fn build(config: &Config) -> Result<Self, Error> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(config.user_agent())
+            .timeout(Duration::from_secs(config.client_timeout()));
+
+        if let Some(proxy) = config.proxy_config() {
+            let mut proxy_builder = reqwest::Proxy::all(proxy.url.as_str())?;
+
+            if let Some((username, password)) = &proxy.auth {
+                proxy_builder = proxy_builder.basic_auth(username, password);
+            }
+
+            builder = builder.proxy(proxy_builder);
+        }
+
+        Ok(Requests {
+            client: builder.build()?,
+        })
+    }
+
+    pub(crate) fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Fan out a delivery of `activity` to every target inbox, running at most
+    /// `config.deliver_concurrency()` deliveries at once so a large relay fanning out to
+    /// thousands of inboxes doesn't open unbounded connections.
+    pub(crate) async fn deliver_many<I>(
+        &self,
+        config: &Config,
+        targets: I,
+        activity: serde_json::Value,
+    ) where
+        I: IntoIterator<Item = reqwest::Url>,
+    {
+        let concurrency = (config.deliver_concurrency() as usize).max(1);
+
+        stream::iter(targets)
+            .map(|target| {
+                let activity = activity.clone();
+                async move { (target.clone(), self.deliver(target, activity).await) }
+            })
+            .buffer_unordered(concurrency)
+            .for_each(|(target, result)| async move {
+                match result {
+                    Ok(()) => crate::metrics::record_activity_delivered(),
+                    Err(e) => {
+                        crate::metrics::record_delivery_failure();
+                        tracing::error!("Error delivering activity to {}, {}", target, e);
+                    }
+                }
+            })
+            .await;
+    }
+
+    async fn deliver(
+        &self,
+        target: reqwest::Url,
+        activity: serde_json::Value,
+    ) -> Result<(), Error> {
+        let started_at = Instant::now();
+        let result = self.deliver_inner(target, activity).await;
+        crate::metrics::record_delivery_duration(started_at.elapsed().as_secs_f64());
+        result
+    }
+
+    async fn deliver_inner(
+        &self,
+        target: reqwest::Url,
+        activity: serde_json::Value,
+    ) -> Result<(), Error> {
+        let body = serde_json::to_vec(&activity)?;
+
+        // Digest hashing is CPU-bound, so it runs on the dedicated signature pool rather
+        // than inline on the actix executor, as HTTP signature verification in `MyVerify`
+        // does. The delivery itself carries only a `Digest` header; it is not signed.
+        let digest_body = body.clone();
+        let digest = signature_pool::run_blocking(move || digest_sha256(&digest_body)).await?;
+
+        self.client
+            .post(target)
+            .header("Content-Type", "application/activity+json")
+            .header("Digest", format!("SHA-256={}", digest))
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+fn digest_sha256(body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(body);
+    base64::encode(hasher.finalize())
+}
@@ -21,6 +21,7 @@ pub(crate) async fn route(
     config: web::Data<Config>,
 ) -> Result<HttpResponse, Error> {
     let all_nodes = state.node_cache().nodes().await?;
+    crate::metrics::set_node_cache_size(all_nodes.len());
 
     let mut nodes = Vec::new();
     let mut local = Vec::new();
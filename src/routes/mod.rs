@@ -0,0 +1,10 @@
+pub(crate) mod index;
+pub(crate) mod nodeinfo;
+
+pub(crate) fn routes(cfg: &mut actix_web::web::ServiceConfig) {
+    cfg.route("/", actix_web::web::get().to(index::route))
+        .route(
+            "/nodeinfo/2.0.json",
+            actix_web::web::get().to(nodeinfo::route),
+        );
+}
@@ -0,0 +1,22 @@
+use crate::{config::Config, error::Error};
+use actix_web::{web, HttpResponse};
+use serde_json::json;
+
+#[tracing::instrument(name = "NodeInfo", skip(config))]
+pub(crate) async fn route(config: web::Data<Config>) -> Result<HttpResponse, Error> {
+    let body = json!({
+        "version": "2.0",
+        "software": {
+            "name": Config::software_name(),
+            "version": Config::software_version(),
+            "repository": config.source_code_for_commit().to_string(),
+        },
+        "protocols": ["activitypub"],
+        "services": { "inbound": [], "outbound": [] },
+        "openRegistrations": false,
+        "usage": { "users": { "total": 1 } },
+        "metadata": {},
+    });
+
+    Ok(HttpResponse::Ok().json(body))
+}
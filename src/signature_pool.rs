@@ -0,0 +1,41 @@
+use crate::error::Error;
+use std::sync::OnceLock;
+use tokio::sync::oneshot;
+
+static POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+
+/// Builds the dedicated thread pool used for CPU-bound HTTP-signature verification and
+/// delivery body digest hashing, sized from `Config::signature_threads`. Call once at startup before
+/// [`run_blocking`] is used.
+pub(crate) fn install(threads: usize) -> Result<(), Error> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|i| format!("signature-{}", i))
+        .build()?;
+
+    POOL.set(pool).ok();
+
+    tracing::info!("Signature thread pool running with {} threads", threads);
+
+    Ok(())
+}
+
+/// Runs `f` on the dedicated signature pool and bridges its result back with a oneshot
+/// channel, so the calling async task yields instead of blocking its actix worker thread on
+/// RSA math.
+pub(crate) async fn run_blocking<F, T>(f: F) -> Result<T, Error>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let pool = POOL
+        .get()
+        .expect("signature_pool::install must run before run_blocking");
+    let (tx, rx) = oneshot::channel();
+
+    pool.spawn(move || {
+        let _ = tx.send(f());
+    });
+
+    Ok(rx.await?)
+}
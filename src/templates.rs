@@ -0,0 +1,53 @@
+use crate::{config::Config, data::Node, error::Error};
+use std::io::Write;
+
+pub(crate) fn index(
+    buf: &mut impl Write,
+    local: &[Node],
+    nodes: &[Node],
+    config: &Config,
+) -> Result<(), Error> {
+    writeln!(buf, "<!DOCTYPE html>")?;
+    writeln!(
+        buf,
+        "<html><head><title>{}</title></head><body>",
+        config.hostname()
+    )?;
+
+    if let Some(blurb) = config.local_blurb() {
+        writeln!(buf, "<section class=\"local-blurb\">{}</section>", blurb)?;
+    }
+
+    writeln!(buf, "<h2>Local instances</h2><ul>")?;
+    for node in local {
+        write_node(buf, node)?;
+    }
+    writeln!(buf, "</ul>")?;
+
+    writeln!(buf, "<h2>Connected instances</h2><ul>")?;
+    for node in nodes {
+        write_node(buf, node)?;
+    }
+    writeln!(buf, "</ul>")?;
+
+    writeln!(buf, "<footer>")?;
+    writeln!(
+        buf,
+        "<p><a href=\"{}\">source code</a> ({})</p>",
+        config.source_code_for_commit(),
+        Config::software_version(),
+    )?;
+    if let Some(blurb) = config.footer_blurb() {
+        writeln!(buf, "<p>{}</p>", blurb)?;
+    }
+    writeln!(buf, "</footer>")?;
+
+    writeln!(buf, "</body></html>")?;
+
+    Ok(())
+}
+
+fn write_node(buf: &mut impl Write, node: &Node) -> Result<(), Error> {
+    writeln!(buf, "<li>{}</li>", node.base)?;
+    Ok(())
+}